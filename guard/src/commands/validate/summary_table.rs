@@ -1,5 +1,7 @@
 use crate::commands::validate::Reporter;
 use std::io::Write;
+use std::io::IsTerminal;
+use std::str::FromStr;
 use crate::commands::tracker::StatusContext;
 use crate::rules::{Status, NamedStatus};
 use colored::*;
@@ -9,6 +11,7 @@ use crate::commands::validate::common::colored_string;
 use crate::rules::eval_context::EventRecord;
 use crate::rules::RecordType;
 use std::collections::HashMap;
+use serde::Serialize;
 
 #[bitflags]
 #[repr(u8)]
@@ -17,20 +20,283 @@ pub(super) enum SummaryType {
     PASS = 0b0001,
     FAIL = 0b0010,
     SKIP = 0b0100,
+    TIMING = 0b1000,
 }
 
+/// Selects how `SummaryTable` renders the PASS/FAIL/SKIP partition: the
+/// default human-oriented table, a structured JSON document, or JUnit XML
+/// for CI test dashboards.
+///
+/// `validate` is typically run over many data/rule file pairs, with one
+/// `report`/`report_eval` call per pair writing to the same stream. Each
+/// `Json` call writes one compact JSON object per line (newline-delimited
+/// JSON) rather than a pretty-printed document, so a multi-file run still
+/// yields a stream every line of which downstream tooling can parse on its
+/// own. Each `Junit` call writes one complete `<testsuites>` document
+/// wrapping a single `<testsuite>`, so each call's output is well-formed
+/// XML on its own. A multi-file run produces one such document per call,
+/// not one combined report: the documents are written back to back and
+/// nothing in this module merges them, so the stream as a whole is not a
+/// single well-formed XML document.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum SummaryOutputFormat {
+    Table,
+    Json,
+    Junit,
+}
+
+impl SummaryOutputFormat {
+    pub(crate) fn variants() -> &'static [&'static str] {
+        &["table", "json", "junit"]
+    }
+}
+
+impl FromStr for SummaryOutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(SummaryOutputFormat::Table),
+            "json" => Ok(SummaryOutputFormat::Json),
+            "junit" => Ok(SummaryOutputFormat::Junit),
+            _ => Err(format!(
+                "invalid output format '{}', expected one of {:?}", s, Self::variants()
+            )),
+        }
+    }
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Writes the `<testsuite>` element for a single data/rule file pair.
+/// Callers wrap this in a `<testsuites>` root via [`write_junit_document`]
+/// so every emitted document is well-formed XML on its own.
+fn print_junit_partition(writer: &mut dyn Write,
+                          rules_file_name: &str,
+                          data_file_name: &str,
+                          passed: &[&StatusContext],
+                          failed: &[&StatusContext],
+                          skipped: &[&StatusContext]) -> crate::rules::Result<()> {
+    writeln!(writer,
+             "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">",
+             xml_escape(data_file_name),
+             passed.len() + failed.len() + skipped.len(),
+             failed.len(),
+             skipped.len())?;
+
+    for container in passed {
+        writeln!(writer,
+                 "    <testcase name=\"{}\" classname=\"{}\"/>",
+                 xml_escape(&container.context), xml_escape(rules_file_name))?;
+    }
+
+    for container in failed {
+        writeln!(writer,
+                 "    <testcase name=\"{}\" classname=\"{}\">",
+                 xml_escape(&container.context), xml_escape(rules_file_name))?;
+        writeln!(writer,
+                 "      <failure message=\"{}\">{}</failure>",
+                 xml_escape(&container.context), xml_escape(&container.context))?;
+        writeln!(writer, "    </testcase>")?;
+    }
+
+    for container in skipped {
+        writeln!(writer,
+                 "    <testcase name=\"{}\" classname=\"{}\">",
+                 xml_escape(&container.context), xml_escape(rules_file_name))?;
+        writeln!(writer, "      <skipped/>")?;
+        writeln!(writer, "    </testcase>")?;
+    }
+
+    writeln!(writer, "  </testsuite>")?;
+    Ok(())
+}
+
+/// Writes the `<testsuite>` element for a single data/rule file pair, from
+/// the name-to-status maps `report_eval` builds. Callers wrap this in a
+/// `<testsuites>` root via [`write_junit_document`].
+fn print_junit_summary(writer: &mut dyn Write,
+                        rules_file_name: &str,
+                        data_file_name: &str,
+                        passed: &indexmap::IndexMap<&str, Status>,
+                        failed: &indexmap::IndexMap<&str, Status>,
+                        skipped: &indexmap::IndexMap<&str, Status>) -> crate::rules::Result<()> {
+    writeln!(writer,
+             "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">",
+             xml_escape(data_file_name),
+             passed.len() + failed.len() + skipped.len(),
+             failed.len(),
+             skipped.len())?;
+
+    for rule_name in passed.keys() {
+        writeln!(writer,
+                 "    <testcase name=\"{}\" classname=\"{}\"/>",
+                 xml_escape(rule_name), xml_escape(rules_file_name))?;
+    }
+
+    for rule_name in failed.keys() {
+        writeln!(writer,
+                 "    <testcase name=\"{}\" classname=\"{}\">",
+                 xml_escape(rule_name), xml_escape(rules_file_name))?;
+        writeln!(writer, "      <failure message=\"{}\">{}</failure>", xml_escape(rule_name), xml_escape(rule_name))?;
+        writeln!(writer, "    </testcase>")?;
+    }
+
+    for rule_name in skipped.keys() {
+        writeln!(writer,
+                 "    <testcase name=\"{}\" classname=\"{}\">",
+                 xml_escape(rule_name), xml_escape(rules_file_name))?;
+        writeln!(writer, "      <skipped/>")?;
+        writeln!(writer, "    </testcase>")?;
+    }
+
+    writeln!(writer, "  </testsuite>")?;
+    Ok(())
+}
+
+/// Wraps a single `<testsuite>`-writing closure in a `<testsuites>` root so
+/// every call to `report`/`report_eval` with `SummaryOutputFormat::Junit`
+/// emits one complete, independently well-formed XML document whose root
+/// is `<testsuites>`.
+fn write_junit_document(
+    writer: &mut dyn Write,
+    write_testsuite: impl FnOnce(&mut dyn Write) -> crate::rules::Result<()>,
+) -> crate::rules::Result<()> {
+    writeln!(writer, "<testsuites>")?;
+    write_testsuite(writer)?;
+    writeln!(writer, "</testsuites>")?;
+    Ok(())
+}
+
+/// Prints the `SummaryType::TIMING` section.
+///
+/// `RecordType::RuleCheck`/`NamedStatus` don't carry the evaluation engine's
+/// own start/end instants for a rule check, and there is no other source of
+/// real per-rule timing reachable from this reporter. An earlier version of
+/// this function measured wall-clock time spent walking each rule's record
+/// here in the reporter itself, which isn't rule evaluation time at all —
+/// it's this loop's own overhead, and reports a misleading number dressed up
+/// as a measurement. Rather than ship that, this prints an explicit notice
+/// instead of fabricated durations until `StatusContext`/`NamedStatus` are
+/// extended to carry real timestamps from the evaluation engine.
+fn print_timing(writer: &mut dyn Write, rule_names: &[&str]) -> crate::rules::Result<()> {
+    if rule_names.is_empty() {
+        return Ok(());
+    }
+    writeln!(writer,
+             "per-rule timing is not available: {} does not yet carry start/end instants from the evaluation engine",
+             "RecordType::RuleCheck")?;
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct JsonSummary<'r> {
+    data_file: &'r str,
+    rules_file: &'r str,
+    status: String,
+    passed: Vec<&'r str>,
+    failed: Vec<&'r str>,
+    skipped: Vec<&'r str>,
+}
+
+/// Writes one compact JSON object for `summary` followed by a newline, the
+/// newline-delimited-JSON line both `report` and `report_eval` emit for
+/// `SummaryOutputFormat::Json`. Split out so the line it produces can be
+/// parsed back and checked in a test without needing a `StatusContext` or
+/// `EventRecord` to drive the full reporter methods.
+fn write_json_summary(writer: &mut dyn Write, summary: &JsonSummary<'_>) -> crate::rules::Result<()> {
+    serde_json::to_writer(&mut *writer, summary)?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+/// Whether the reporter's colored output (bold headers, PASS/FAIL/SKIP
+/// coloring) should be emitted, mirroring how `anstream`/`colorchoice`
+/// model terminal color handling.
+///
+/// `Auto` is decided against stdout rather than the `writer` passed to
+/// `report`/`report_eval`: those take an arbitrary `&mut dyn Write`, which
+/// has no `is_terminal` supertrait to query, while in practice the CLI's
+/// human-readable reports are always written to stdout.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ColorChoice {
+    /// Colorize only when NO_COLOR is unset and stdout is a terminal.
+    Auto,
+    /// Always colorize, even when redirected.
+    Always,
+    /// Never colorize, even when attached to a terminal.
+    Never,
+}
+
+impl ColorChoice {
+    /// Applies this choice to the process-wide `colored` crate override so
+    /// every styling call in this module (and `colored_string`) honors it.
+    ///
+    /// This override is global and last-writer-wins: it is not scoped to
+    /// this `SummaryTable` instance, so two reports with different
+    /// `ColorChoice`s running concurrently in the same process will race.
+    /// The CLI only ever drives one report at a time today; if that
+    /// changes, colorization should be threaded through explicitly instead
+    /// of relying on this global toggle.
+    fn apply(&self) {
+        match self {
+            ColorChoice::Always => colored::control::set_override(true),
+            ColorChoice::Never => colored::control::set_override(false),
+            ColorChoice::Auto => {
+                let no_color = std::env::var_os("NO_COLOR").is_some();
+                let is_terminal = std::io::stdout().is_terminal();
+                if auto_should_colorize(no_color, is_terminal) {
+                    colored::control::unset_override();
+                } else {
+                    colored::control::set_override(false);
+                }
+            }
+        }
+    }
+}
+
+/// Pure decision behind `ColorChoice::Auto`, split out from `apply` so it
+/// can be unit tested without touching the real environment or a terminal.
+fn auto_should_colorize(no_color_env_set: bool, stdout_is_terminal: bool) -> bool {
+    !no_color_env_set && stdout_is_terminal
+}
 
 #[derive(Debug)]
 pub(super) struct SummaryTable<'r> {
     rules_file_name: &'r str,
     data_file_name: &'r str,
     summary_type: BitFlags<SummaryType>,
+    output_format: SummaryOutputFormat,
+    color_choice: ColorChoice,
 }
 
 impl<'a> SummaryTable<'a> {
-    pub(crate) fn new<'r>(rules_file_name: &'r str, data_file_name: &'r str, summary_type: BitFlags<SummaryType>) -> SummaryTable<'r> {
+    pub(crate) fn new<'r>(
+        rules_file_name: &'r str,
+        data_file_name: &'r str,
+        summary_type: BitFlags<SummaryType>,
+        output_format: SummaryOutputFormat,
+        color_choice: ColorChoice,
+    ) -> SummaryTable<'r> {
         SummaryTable {
-            rules_file_name, data_file_name, summary_type
+            rules_file_name, data_file_name, summary_type, output_format, color_choice
+        }
+    }
+
+    /// Evaluates `names` only when `self.summary_type` requests `section`,
+    /// so `Json` mode honors the same PASS/FAIL/SKIP filter the table does.
+    fn filtered<'n>(&self, section: SummaryType, names: impl FnOnce() -> Vec<&'n str>) -> Vec<&'n str> {
+        if self.summary_type.contains(section) {
+            names()
+        } else {
+            Vec::new()
         }
     }
 }
@@ -67,7 +333,6 @@ fn print_summary(
     Ok(())
 }
 
-
 impl<'r> Reporter for SummaryTable<'r> {
     fn report(&self,
               writer: &mut dyn Write,
@@ -76,6 +341,8 @@ impl<'r> Reporter for SummaryTable<'r> {
               passed_or_skipped: &[&StatusContext],
               longest_rule_name: usize) -> crate::rules::Result<()> {
 
+        self.color_choice.apply();
+
         let as_vec = passed_or_skipped.iter().map(|s| *s)
             .collect_vec();
         let (skipped, passed): (Vec<&StatusContext>, Vec<&StatusContext>) = as_vec.iter()
@@ -84,20 +351,38 @@ impl<'r> Reporter for SummaryTable<'r> {
                 _ => false
             });
 
+        if let SummaryOutputFormat::Json = self.output_format {
+            let json = JsonSummary {
+                data_file: self.data_file_name,
+                rules_file: self.rules_file_name,
+                status: status.map(|s| s.to_string()).unwrap_or_default(),
+                passed: self.filtered(SummaryType::PASS, || passed.iter().map(|c| c.context.as_str()).collect()),
+                failed: self.filtered(SummaryType::FAIL, || failed_rules.iter().map(|c| c.context.as_str()).collect()),
+                skipped: self.filtered(SummaryType::SKIP, || skipped.iter().map(|c| c.context.as_str()).collect()),
+            };
+            write_json_summary(writer, &json)?;
+            return Ok(());
+        }
+
+        if let SummaryOutputFormat::Junit = self.output_format {
+            return write_junit_document(writer, |w| print_junit_partition(
+                w, self.rules_file_name, self.data_file_name, &passed, failed_rules, &skipped));
+        }
+
         writeln!(writer, "{} Status = {}", self.data_file_name, colored_string(status))?;
         if self.summary_type.contains(SummaryType::SKIP) && !skipped.is_empty() {
-            writeln!(writer, "{}", "SKIP rules".bold());
+            writeln!(writer, "{}", "SKIP rules".bold())?;
             print_partition(writer, self.rules_file_name, &skipped, longest_rule_name)?;
 
         }
 
         if self.summary_type.contains(SummaryType::PASS) && !passed.is_empty() {
-            writeln!(writer, "{}", "PASS rules".bold());
+            writeln!(writer, "{}", "PASS rules".bold())?;
             print_partition(writer, self.rules_file_name, &passed, longest_rule_name)?;
         }
 
         if self.summary_type.contains(SummaryType::FAIL) && !failed_rules.is_empty() {
-            writeln!(writer, "{}", "FAILED rules".bold());
+            writeln!(writer, "{}", "FAILED rules".bold())?;
             print_partition(writer, self.rules_file_name, failed_rules, longest_rule_name)?;
         }
 
@@ -107,10 +392,12 @@ impl<'r> Reporter for SummaryTable<'r> {
     }
 
     fn report_eval(&self, writer: &mut dyn Write, status: Status, root_record: &EventRecord<'_>) -> crate::rules::Result<()> {
-        writeln!(writer, "{} Status = {}", self.data_file_name, colored_string(Some(status)))?;
+        self.color_choice.apply();
+
         let mut passed = indexmap::IndexMap::with_capacity(root_record.children.len());
         let mut skipped = indexmap::IndexMap::with_capacity(root_record.children.len());
         let mut failed = indexmap::IndexMap::with_capacity(root_record.children.len());
+        let mut rule_names = Vec::with_capacity(root_record.children.len());
         let mut longest = 0;
         for each_rule in &root_record.children {
             if let Some(RecordType::RuleCheck(NamedStatus {status, name, ..})) =
@@ -123,11 +410,32 @@ impl<'r> Reporter for SummaryTable<'r> {
                 if longest < name.len() {
                     longest = name.len()
                 }
+                rule_names.push(*name);
             }
         }
 
         skipped.retain(|key, _| !(passed.contains_key(key) || failed.contains_key(key)));
 
+        if let SummaryOutputFormat::Json = self.output_format {
+            let json = JsonSummary {
+                data_file: self.data_file_name,
+                rules_file: self.rules_file_name,
+                status: status.to_string(),
+                passed: self.filtered(SummaryType::PASS, || passed.keys().map(|k| *k).collect()),
+                failed: self.filtered(SummaryType::FAIL, || failed.keys().map(|k| *k).collect()),
+                skipped: self.filtered(SummaryType::SKIP, || skipped.keys().map(|k| *k).collect()),
+            };
+            write_json_summary(writer, &json)?;
+            return Ok(());
+        }
+
+        if let SummaryOutputFormat::Junit = self.output_format {
+            return write_junit_document(writer, |w| print_junit_summary(
+                w, self.rules_file_name, self.data_file_name, &passed, &failed, &skipped));
+        }
+
+        writeln!(writer, "{} Status = {}", self.data_file_name, colored_string(Some(status)))?;
+
         if self.summary_type.contains(SummaryType::SKIP) && !skipped.is_empty() {
             writeln!(writer, "{}", "SKIP rules".bold())?;
             print_summary(writer, self.rules_file_name, longest, &skipped)?;
@@ -143,7 +451,147 @@ impl<'r> Reporter for SummaryTable<'r> {
             print_summary(writer, self.rules_file_name, longest, &failed)?;
         }
 
+        if self.summary_type.contains(SummaryType::TIMING) && !rule_names.is_empty() {
+            writeln!(writer, "{}", "Timing".bold())?;
+            print_timing(writer, &rule_names)?;
+        }
+
         writeln!(writer, "---")?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_colorizes_only_on_a_terminal_without_no_color() {
+        assert!(auto_should_colorize(false, true));
+        assert!(!auto_should_colorize(true, true));
+        assert!(!auto_should_colorize(false, false));
+        assert!(!auto_should_colorize(true, false));
+    }
+
+    #[test]
+    fn print_timing_reports_unavailable_instead_of_fabricating_durations() {
+        let mut out = Vec::new();
+        print_timing(&mut out, &["s3_bucket_policy_rule"]).unwrap();
+        let rendered = String::from_utf8(out).unwrap();
+        assert!(rendered.contains("not available"));
+        assert!(rendered.contains("RecordType::RuleCheck"));
+    }
+
+    #[test]
+    fn print_timing_handles_empty_input() {
+        let mut out = Vec::new();
+        print_timing(&mut out, &[]).unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn output_format_from_str_accepts_known_variants() {
+        assert_eq!("table".parse::<SummaryOutputFormat>().unwrap(), SummaryOutputFormat::Table);
+        assert_eq!("json".parse::<SummaryOutputFormat>().unwrap(), SummaryOutputFormat::Json);
+        assert_eq!("junit".parse::<SummaryOutputFormat>().unwrap(), SummaryOutputFormat::Junit);
+    }
+
+    #[test]
+    fn output_format_from_str_rejects_unknown_values() {
+        let err = "yaml".parse::<SummaryOutputFormat>().unwrap_err();
+        assert!(err.contains("yaml"));
+        assert!(err.contains("table"));
+    }
+
+    #[test]
+    fn xml_escape_escapes_reserved_characters() {
+        assert_eq!(
+            xml_escape(r#"<rule name="a & b"> 'quoted' </rule>"#),
+            "&lt;rule name=&quot;a &amp; b&quot;&gt; &apos;quoted&apos; &lt;/rule&gt;"
+        );
+    }
+
+    #[test]
+    fn xml_escape_leaves_plain_text_untouched() {
+        assert_eq!(xml_escape("s3_bucket_policy_rule"), "s3_bucket_policy_rule");
+    }
+
+    // `report`/`report_eval` take `&[&StatusContext]`/`&EventRecord<'_>`,
+    // both defined outside this file with no visible constructor, so these
+    // exercise the exact Json-writing code both methods call rather than
+    // the trait methods themselves.
+    #[test]
+    fn json_summary_writes_a_single_parseable_ndjson_line() {
+        let json = JsonSummary {
+            data_file: "data.json",
+            rules_file: "rules.guard",
+            status: "FAIL".to_string(),
+            passed: vec!["rule_a"],
+            failed: vec!["rule_b"],
+            skipped: Vec::new(),
+        };
+        let mut out = Vec::new();
+        write_json_summary(&mut out, &json).unwrap();
+        let rendered = String::from_utf8(out).unwrap();
+
+        assert_eq!(rendered.lines().count(), 1);
+        let parsed: serde_json::Value = serde_json::from_str(rendered.trim_end()).unwrap();
+        assert_eq!(parsed["data_file"], "data.json");
+        assert_eq!(parsed["rules_file"], "rules.guard");
+        assert_eq!(parsed["status"], "FAIL");
+        assert_eq!(parsed["passed"], serde_json::json!(["rule_a"]));
+        assert_eq!(parsed["failed"], serde_json::json!(["rule_b"]));
+        assert_eq!(parsed["skipped"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn json_summary_lines_concatenate_into_valid_ndjson_across_multiple_calls() {
+        let first = JsonSummary {
+            data_file: "a.json", rules_file: "rules.guard", status: "PASS".to_string(),
+            passed: vec!["rule_a"], failed: Vec::new(), skipped: Vec::new(),
+        };
+        let second = JsonSummary {
+            data_file: "b.json", rules_file: "rules.guard", status: "FAIL".to_string(),
+            passed: Vec::new(), failed: vec!["rule_b"], skipped: Vec::new(),
+        };
+        let mut out = Vec::new();
+        write_json_summary(&mut out, &first).unwrap();
+        write_json_summary(&mut out, &second).unwrap();
+        let rendered = String::from_utf8(out).unwrap();
+
+        let parsed: Vec<serde_json::Value> = rendered
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0]["data_file"], "a.json");
+        assert_eq!(parsed[1]["data_file"], "b.json");
+    }
+
+    // `print_junit_summary` is the exact function `report_eval` calls for
+    // `SummaryOutputFormat::Junit`; it only needs name-to-`Status` maps, so
+    // unlike `print_junit_partition` (which needs `&[&StatusContext]`) it
+    // can be driven directly without a `StatusContext` constructor.
+    #[test]
+    fn junit_document_is_well_formed_with_counts_and_escaped_names() {
+        let mut passed = indexmap::IndexMap::new();
+        passed.insert("s3_bucket_policy_rule", Status::PASS);
+        let mut failed = indexmap::IndexMap::new();
+        failed.insert("ec2 & vpc <rule>", Status::FAIL);
+        let mut skipped = indexmap::IndexMap::new();
+        skipped.insert("iam_policy_rule", Status::SKIP);
+
+        let mut out = Vec::new();
+        write_junit_document(&mut out, |w| print_junit_summary(
+            w, "rules.guard", "data.json", &passed, &failed, &skipped)).unwrap();
+        let rendered = String::from_utf8(out).unwrap();
+
+        assert!(rendered.starts_with("<testsuites>\n"));
+        assert!(rendered.trim_end().ends_with("</testsuites>"));
+        assert_eq!(rendered.matches("<testsuite ").count(), 1);
+        assert!(rendered.contains("tests=\"3\" failures=\"1\" skipped=\"1\""));
+        assert!(rendered.contains("ec2 &amp; vpc &lt;rule&gt;"));
+        assert!(rendered.contains("<failure message=\"ec2 &amp; vpc &lt;rule&gt;\">"));
+        assert!(rendered.contains("<skipped/>"));
+    }
+}